@@ -46,32 +46,60 @@
 //!
 //! - **`start_sync()`**: Start file watcher with interval
 //! - **`stop_sync()`**: Stop file watcher
-//! - **`trigger_sync()`**: Manually trigger immediate sync (async, returns immediately)
+//! - **`trigger_sync()`**: Manually trigger immediate sync (async, returns immediately);
+//!   rejected with `GraphitiError::SyncInProgress` while another trigger is in flight
+//!
+//! `start_sync()` and `trigger_sync()` retry transient failures (connection errors and
+//! 5xx responses) with exponential backoff (`sync_retry_attempts` retries, 1s doubling) so
+//! a Graphiti hiccup at startup doesn't silently leave the corpus unsynced. 4xx responses
+//! and timeouts are not retried, since the trigger may already have been accepted.
 //!
 //! # Error Handling
 //!
 //! All methods return `Result<T, GraphitiError>` with:
 //! - `GraphitiError::Http`: Network/connection failures
-//! - `GraphitiError::Request`: Non-2xx HTTP status codes with server error message
+//! - `GraphitiError::Request`: Non-2xx HTTP status code with server error message
 //! - `GraphitiError::InvalidResponse`: JSON deserialization failures
+//! - `GraphitiError::SyncInProgress`: `trigger_sync()` while another trigger is in flight
 //!
 //! # Configuration
 //!
 //! The client is configured via `GraphitiConfig`:
 //! - `base_url`: Graphiti server URL (default: `http://localhost:8000`)
 //! - `timeout_secs`: Request timeout (default: 30 seconds)
+//! - `sync_retry_attempts`: Retries for sync start/trigger calls (default: 3)
 
 use crate::config::GraphitiConfig;
 use crate::error::GraphitiError;
-use reqwest::Client;
+use reqwest::{Client, Response};
 use serde_json::{json, Value};
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
+use tokio::time::sleep;
+
+/// Delay before the first retry of a failed sync call (doubles on each further retry)
+const SYNC_RETRY_BASE_DELAY: Duration = Duration::from_secs(1);
 
 /// HTTP client for Graphiti `FastAPI` backend
 #[derive(Clone)]
 pub struct GraphitiClient {
     client: Client,
     base_url: String,
+    sync_retry_attempts: u32,
+    sync_retry_delay: Duration,
+    /// Shared by all clones, so startup and tool-initiated triggers exclude each other
+    sync_trigger_in_flight: Arc<AtomicBool>,
+}
+
+/// Clears the in-flight flag when a sync trigger finishes (or its future is dropped)
+struct SyncTriggerGuard(Arc<AtomicBool>);
+
+impl Drop for SyncTriggerGuard {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::Release);
+    }
 }
 
 impl GraphitiClient {
@@ -85,9 +113,46 @@ impl GraphitiClient {
         Ok(Self {
             client,
             base_url: config.base_url.clone(),
+            sync_retry_attempts: config.sync_retry_attempts,
+            sync_retry_delay: SYNC_RETRY_BASE_DELAY,
+            sync_trigger_in_flight: Arc::new(AtomicBool::new(false)),
         })
     }
 
+    /// Run a sync call, retrying transient failures with exponential backoff
+    ///
+    /// Makes up to `1 + sync_retry_attempts` attempts (1s, 2s, 4s, ... between them).
+    /// Only connection failures and 5xx responses are retried (see
+    /// `GraphitiError::is_transient`); anything else, including a timeout after the
+    /// request may have been processed, is returned immediately.
+    async fn with_sync_retries<T, F, Fut>(
+        &self,
+        operation: &str,
+        mut call: F,
+    ) -> Result<T, GraphitiError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, GraphitiError>>,
+    {
+        let mut delay = self.sync_retry_delay;
+
+        for retry in 1..=self.sync_retry_attempts {
+            match call().await {
+                Err(e) if e.is_transient() => {
+                    tracing::warn!(
+                        "{operation} failed (retry {retry}/{} in {delay:?}): {e}",
+                        self.sync_retry_attempts
+                    );
+                    sleep(delay).await;
+                    delay *= 2;
+                }
+                result => return result,
+            }
+        }
+
+        call().await
+    }
+
     /// Add episode to knowledge graph
     /// POST /episodes
     /// Hardcodes source='text' and `group_id`='default' for simplicity
@@ -118,13 +183,7 @@ impl GraphitiClient {
             .await
             .map_err(GraphitiError::Http)?;
 
-        if !response.status().is_success() {
-            return Err(GraphitiError::Request(format!(
-                "HTTP {}: {}",
-                response.status(),
-                response.text().await.unwrap_or_default()
-            )));
-        }
+        check_status(response).await?;
 
         Ok("Episode queued for processing".to_string())
     }
@@ -158,13 +217,7 @@ impl GraphitiClient {
             .await
             .map_err(GraphitiError::Http)?;
 
-        if !response.status().is_success() {
-            return Err(GraphitiError::Request(format!(
-                "HTTP {}: {}",
-                response.status(),
-                response.text().await.unwrap_or_default()
-            )));
-        }
+        let response = check_status(response).await?;
 
         response
             .json()
@@ -201,13 +254,7 @@ impl GraphitiClient {
             .await
             .map_err(GraphitiError::Http)?;
 
-        if !response.status().is_success() {
-            return Err(GraphitiError::Request(format!(
-                "HTTP {}: {}",
-                response.status(),
-                response.text().await.unwrap_or_default()
-            )));
-        }
+        let response = check_status(response).await?;
 
         response
             .json()
@@ -246,13 +293,7 @@ impl GraphitiClient {
             .await
             .map_err(GraphitiError::Http)?;
 
-        if !response.status().is_success() {
-            return Err(GraphitiError::Request(format!(
-                "HTTP {}: {}",
-                response.status(),
-                response.text().await.unwrap_or_default()
-            )));
-        }
+        let response = check_status(response).await?;
 
         response
             .json()
@@ -280,13 +321,7 @@ impl GraphitiClient {
             .await
             .map_err(GraphitiError::Http)?;
 
-        if !response.status().is_success() {
-            return Err(GraphitiError::Request(format!(
-                "HTTP {}: {}",
-                response.status(),
-                response.text().await.unwrap_or_default()
-            )));
-        }
+        let response = check_status(response).await?;
 
         response
             .json()
@@ -306,24 +341,31 @@ impl GraphitiClient {
             .await
             .map_err(GraphitiError::Http)?;
 
-        if !response.status().is_success() {
-            return Err(GraphitiError::Request(format!(
-                "HTTP {}: {}",
-                response.status(),
-                response.text().await.unwrap_or_default()
-            )));
-        }
+        check_status(response).await?;
 
         Ok(format!("Episode {uuid} deleted"))
     }
 
-    /// Start document sync watcher
+    /// Start document sync watcher, retrying transient failures
     /// POST /sync/start
     pub async fn start_sync(
         &self,
         corpus_path: &str,
         sync_interval_hours: f64,
         group_id: &str,
+    ) -> Result<String, GraphitiError> {
+        self.with_sync_retries("Start document sync", || {
+            self.start_sync_once(corpus_path, sync_interval_hours, group_id)
+        })
+        .await
+    }
+
+    /// Single `POST /sync/start` attempt
+    async fn start_sync_once(
+        &self,
+        corpus_path: &str,
+        sync_interval_hours: f64,
+        group_id: &str,
     ) -> Result<String, GraphitiError> {
         let url = format!("{}/sync/start", self.base_url);
 
@@ -341,13 +383,7 @@ impl GraphitiClient {
             .await
             .map_err(GraphitiError::Http)?;
 
-        if !response.status().is_success() {
-            return Err(GraphitiError::Request(format!(
-                "HTTP {}: {}",
-                response.status(),
-                response.text().await.unwrap_or_default()
-            )));
-        }
+        let response = check_status(response).await?;
 
         let result: Value = response
             .json()
@@ -372,13 +408,7 @@ impl GraphitiClient {
             .await
             .map_err(GraphitiError::Http)?;
 
-        if !response.status().is_success() {
-            return Err(GraphitiError::Request(format!(
-                "HTTP {}: {}",
-                response.status(),
-                response.text().await.unwrap_or_default()
-            )));
-        }
+        let response = check_status(response).await?;
 
         let result: Value = response
             .json()
@@ -391,11 +421,26 @@ impl GraphitiClient {
             .to_string())
     }
 
-    /// Trigger manual document sync (always async)
+    /// Trigger manual document sync (always async), retrying transient failures
     /// POST /sync/trigger
     ///
-    /// Triggers document sync in background and returns immediately.
+    /// Triggers document sync in background and returns once Graphiti accepts it.
+    /// While a trigger (including its retries) is in flight on any clone of this client,
+    /// further calls fail with `GraphitiError::SyncInProgress`. Graphiti doesn't report
+    /// sync progress, so a sync still running in the background afterwards isn't detected.
     pub async fn trigger_sync(&self) -> Result<String, GraphitiError> {
+        // Trigger calls retry with backoff, so overlapping requests would just pile up
+        if self.sync_trigger_in_flight.swap(true, Ordering::AcqRel) {
+            return Err(GraphitiError::SyncInProgress);
+        }
+        let _guard = SyncTriggerGuard(Arc::clone(&self.sync_trigger_in_flight));
+
+        self.with_sync_retries("Trigger document sync", || self.trigger_sync_once())
+            .await
+    }
+
+    /// Single `POST /sync/trigger` attempt
+    async fn trigger_sync_once(&self) -> Result<String, GraphitiError> {
         let url = format!("{}/sync/trigger", self.base_url);
 
         let response = self
@@ -405,15 +450,131 @@ impl GraphitiClient {
             .await
             .map_err(GraphitiError::Http)?;
 
-        if !response.status().is_success() {
-            return Err(GraphitiError::Request(format!(
-                "HTTP {}: {}",
-                response.status(),
-                response.text().await.unwrap_or_default()
-            )));
-        }
+        let response = check_status(response).await?;
 
         let _ = response.text().await;
         Ok("Document sync started in background".to_string())
     }
 }
+
+/// Turn a non-2xx response into `GraphitiError::Request` carrying the status and body
+async fn check_status(response: Response) -> Result<Response, GraphitiError> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+
+    Err(GraphitiError::Request {
+        status,
+        body: response.text().await.unwrap_or_default(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::MockGraphiti;
+
+    fn test_client(base_url: &str, sync_retry_attempts: u32) -> GraphitiClient {
+        let config = GraphitiConfig {
+            base_url: base_url.to_string(),
+            sync_retry_attempts,
+            ..GraphitiConfig::default()
+        };
+        let mut client = GraphitiClient::new(&config).expect("build client");
+        client.sync_retry_delay = Duration::from_millis(10);
+        client
+    }
+
+    #[tokio::test]
+    async fn trigger_sync_retries_server_errors_until_success() {
+        let mock = MockGraphiti::start(vec![
+            (503, r#"{"detail": "starting"}"#),
+            (503, r#"{"detail": "starting"}"#),
+            (200, r#"{"message": "ok"}"#),
+        ])
+        .await;
+        let client = test_client(&mock.base_url, 3);
+
+        let result = client.trigger_sync().await;
+
+        assert!(result.is_ok(), "expected success, got {result:?}");
+        assert_eq!(mock.requests(), 3);
+    }
+
+    #[tokio::test]
+    async fn trigger_sync_does_not_retry_client_errors() {
+        let mock = MockGraphiti::start(vec![(400, r#"{"detail": "bad request"}"#)]).await;
+        let client = test_client(&mock.base_url, 3);
+
+        let result = client.trigger_sync().await;
+
+        assert!(matches!(
+            result,
+            Err(GraphitiError::Request { status, .. }) if status == reqwest::StatusCode::BAD_REQUEST
+        ));
+        assert_eq!(mock.requests(), 1);
+    }
+
+    #[tokio::test]
+    async fn start_sync_gives_up_after_retry_attempts() {
+        let mock = MockGraphiti::start(vec![(500, r#"{"detail": "down"}"#)]).await;
+        let client = test_client(&mock.base_url, 2);
+
+        let result = client.start_sync("/srv/corpus", 1.0, "default").await;
+
+        assert!(matches!(result, Err(GraphitiError::Request { .. })));
+        assert_eq!(mock.requests(), 3);
+    }
+
+    /// Wait until the mock has received `count` requests
+    async fn wait_for_requests(mock: &MockGraphiti, count: usize) {
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while mock.requests() < count {
+                sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("request never arrived");
+    }
+
+    #[tokio::test]
+    async fn trigger_sync_rejects_overlapping_calls_across_clones() {
+        let mock =
+            MockGraphiti::start_with_delay(vec![(200, "{}")], Duration::from_millis(300)).await;
+        let client = test_client(&mock.base_url, 0);
+
+        let first = tokio::spawn({
+            let client = client.clone();
+            async move { client.trigger_sync().await }
+        });
+        wait_for_requests(&mock, 1).await;
+
+        let second = client.trigger_sync().await;
+        assert!(matches!(second, Err(GraphitiError::SyncInProgress)));
+
+        first
+            .await
+            .expect("first trigger task")
+            .expect("first trigger succeeds");
+        assert!(!client.sync_trigger_in_flight.load(Ordering::Acquire));
+        assert_eq!(mock.requests(), 1);
+    }
+
+    #[tokio::test]
+    async fn trigger_sync_clears_flag_when_cancelled() {
+        let mock = MockGraphiti::start_with_delay(vec![(200, "{}")], Duration::from_secs(30)).await;
+        let client = test_client(&mock.base_url, 0);
+
+        let trigger = tokio::spawn({
+            let client = client.clone();
+            async move { client.trigger_sync().await }
+        });
+        wait_for_requests(&mock, 1).await;
+        assert!(client.sync_trigger_in_flight.load(Ordering::Acquire));
+
+        trigger.abort();
+        assert!(trigger.await.is_err_and(|e| e.is_cancelled()));
+        assert!(!client.sync_trigger_in_flight.load(Ordering::Acquire));
+    }
+}
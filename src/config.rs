@@ -29,11 +29,31 @@
 //!    - Logs warning
 //!    - Corpus path defaults to None (document sync disabled)
 //!
+//! # Environment Overrides
+//!
+//! After the file (or defaults) is loaded, `CYMBIONT_<SECTION>_<FIELD>` environment
+//! variables override individual values, e.g. `CYMBIONT_GRAPHITI_BASE_URL`. Intended for
+//! containerized deployments where editing config.yaml is inconvenient. Overridable:
+//!
+//! - **graphiti**: `BASE_URL`, `TIMEOUT_SECS`, `DEFAULT_GROUP_ID`, `SERVER_PATH`,
+//!   `STARTUP_TIMEOUT_SECS`, `MAX_STARTUP_ATTEMPTS`, `KILL_ON_EXIT`, `SYNC_RETRY_ATTEMPTS`
+//! - **server**: `READ_ONLY`, `PREVIEW_CHARS` (empty string means full content)
+//! - **corpus**: `PATH` (empty string disables sync), `SYNC_INTERVAL_HOURS`
+//! - **logging**: `LEVEL`, `LOG_DIRECTORY`, `MAX_FILES`, `MAX_SIZE_MB`
+//!
+//! Values that fail to parse (e.g. `CYMBIONT_GRAPHITI_TIMEOUT_SECS=abc`) or fail path
+//! validation are reported as `ConfigError::Env`, which aborts startup instead of falling
+//! back to defaults. Without a config file, only env-overridden paths are validated.
+//! When config.yaml fails to load, main.rs falls back to `Config::from_defaults_with_env`,
+//! so overrides still apply.
+//!
 //! # Configuration Structure
 //!
 //! The config file is divided into logical sections:
 //!
-//! - **graphiti**: Graphiti backend connection (`base_url`, timeout, `server_path`)
+//! - **graphiti**: Graphiti backend connection (`base_url`, timeout, `server_path`),
+//!   startup polling, `kill_on_exit` and sync retries
+//! - **server**: MCP server behavior (`read_only`, `preview_chars`)
 //! - **corpus**: Document sync settings (path, `sync_interval_hours`)
 //! - **logging**: Log output configuration (level, directory, rotation)
 //! - **verbosity**: Autodebugger verbosity monitoring thresholds
@@ -57,6 +77,14 @@
 //!   timeout_secs: 30
 //!   default_group_id: "default"
 //!   server_path: "/absolute/path/to/graphiti-cymbiont"
+//!   startup_timeout_secs: 30
+//!   max_startup_attempts: 20
+//!   kill_on_exit: true
+//!   sync_retry_attempts: 3
+//!
+//! server:
+//!   read_only: false
+//!   preview_chars: 500
 //!
 //! corpus:
 //!   path: "/absolute/path/to/corpus"
@@ -73,14 +101,17 @@ use crate::error::ConfigError;
 use directories::ProjectDirs;
 use serde::{Deserialize, Serialize};
 use std::env;
+use std::fmt::Display;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 /// Root configuration structure
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(default)]
 pub struct Config {
     pub graphiti: GraphitiConfig,
+    pub server: ServerConfig,
     pub corpus: CorpusConfig,
     pub logging: LoggingConfig,
     pub verbosity: VerbosityConfig,
@@ -96,12 +127,28 @@ pub struct GraphitiConfig {
     pub default_group_id: String,
     #[serde(default = "default_server_path")]
     pub server_path: String,
+    pub startup_timeout_secs: u64,
+    pub max_startup_attempts: u32,
+    pub kill_on_exit: bool,
+    pub sync_retry_attempts: u32,
 }
 
 fn default_server_path() -> String {
     "../../graphiti-cymbiont/server".to_string()
 }
 
+/// MCP server behavior
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ServerConfig {
+    /// Reject mutating tools (`add_memory`, `delete_episode`, `sync_documents`) and skip
+    /// document sync, while search and episode reads keep working
+    pub read_only: bool,
+    /// Default character limit for text returned by `get_episodes`, `search_context` and
+    /// `get_chunks` (None = full content)
+    pub preview_chars: Option<usize>,
+}
+
 /// Document corpus sync configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -150,6 +197,10 @@ impl Default for GraphitiConfig {
             timeout_secs: 30,
             default_group_id: "default".to_string(),
             server_path: "../../graphiti-cymbiont/server".to_string(), // Bundled graphiti-cymbiont
+            startup_timeout_secs: 30,
+            max_startup_attempts: 20,
+            kill_on_exit: true,
+            sync_retry_attempts: 3,
         }
     }
 }
@@ -188,6 +239,93 @@ impl Default for VerbosityConfig {
 
 // Config loading
 
+/// Source of environment variable values (tests substitute a fixed table)
+type EnvLookup = fn(&str) -> Option<String>;
+
+/// Read a variable from the process environment (non-Unicode values count as unset)
+fn process_env(name: &str) -> Option<String> {
+    env::var(name).ok()
+}
+
+/// Tracks which `CYMBIONT_<SECTION>_<FIELD>` environment variables were applied
+#[derive(Debug)]
+struct EnvOverrides {
+    lookup: EnvLookup,
+    applied: Vec<&'static str>,
+}
+
+impl Default for EnvOverrides {
+    fn default() -> Self {
+        Self::with_lookup(process_env)
+    }
+}
+
+impl EnvOverrides {
+    fn with_lookup(lookup: EnvLookup) -> Self {
+        Self {
+            lookup,
+            applied: Vec::new(),
+        }
+    }
+
+    /// Parse environment variable `name` into `target` if set
+    fn apply<T>(&mut self, name: &'static str, target: &mut T) -> Result<(), ConfigError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let Some(value) = (self.lookup)(name) else {
+            return Ok(());
+        };
+
+        *target = parse_env_value(name, &value)?;
+        self.record(name);
+        Ok(())
+    }
+
+    /// Like `apply`, for optional fields: an empty value clears the field
+    fn apply_optional<T>(
+        &mut self,
+        name: &'static str,
+        target: &mut Option<T>,
+    ) -> Result<(), ConfigError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let Some(value) = (self.lookup)(name) else {
+            return Ok(());
+        };
+
+        *target = if value.is_empty() {
+            None
+        } else {
+            Some(parse_env_value(name, &value)?)
+        };
+        self.record(name);
+        Ok(())
+    }
+
+    fn record(&mut self, name: &'static str) {
+        tracing::trace!("Config override from environment: {name}");
+        self.applied.push(name);
+    }
+
+    fn contains(&self, name: &str) -> bool {
+        self.applied.contains(&name)
+    }
+}
+
+fn parse_env_value<T>(name: &str, value: &str) -> Result<T, ConfigError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value
+        .parse()
+        .map_err(|e| ConfigError::Env(format!("Invalid value for {name} ({value:?}): {e}")))
+}
+
 impl Config {
     /// Load config from config.yaml with flexible location search
     ///
@@ -197,25 +335,108 @@ impl Config {
     /// 3. cymbiont/config.yaml (repo root, relative to binary)
     /// 4. ~/.config/cymbiont/config.yaml (XDG standard location)
     /// 5. Defaults (if no config file found)
+    ///
+    /// `CYMBIONT_<SECTION>_<FIELD>` environment variables are then applied on top.
     pub fn load() -> Result<Self, ConfigError> {
-        let config_path = Self::find_config_file()?;
+        let Some(path) = Self::find_config_file()? else {
+            tracing::warn!("No config.yaml found, using defaults");
+            return Self::from_defaults_with_env();
+        };
 
-        if let Some(path) = config_path {
-            tracing::info!("Loading config from: {}", path.display());
+        tracing::info!("Loading config from: {}", path.display());
 
-            let contents = fs::read_to_string(&path).map_err(|e| ConfigError::Io(e.to_string()))?;
+        let contents = fs::read_to_string(&path).map_err(|e| ConfigError::Io(e.to_string()))?;
+        let config: Self =
+            serde_yaml::from_str(&contents).map_err(|e| ConfigError::Parse(e.to_string()))?;
 
-            let mut config: Self =
-                serde_yaml::from_str(&contents).map_err(|e| ConfigError::Parse(e.to_string()))?;
+        config.with_env_overrides(true, process_env)
+    }
 
-            // Validate and enforce absolute paths
-            config.validate_paths()?;
+    /// Built-in defaults with `CYMBIONT_*` environment overrides applied
+    ///
+    /// Used when no config file exists, and by main.rs when config.yaml can't be loaded,
+    /// so environment overrides are honored either way. Only env-overridden paths are
+    /// validated.
+    pub fn from_defaults_with_env() -> Result<Self, ConfigError> {
+        Self::default().with_env_overrides(false, process_env)
+    }
 
-            Ok(config)
-        } else {
-            tracing::warn!("No config.yaml found, using defaults");
-            Ok(Self::default())
-        }
+    /// Apply environment overrides (environment wins over YAML values), then validate
+    ///
+    /// With `validate_all` false, only paths set from the environment are checked;
+    /// built-in defaults are left as-is.
+    fn with_env_overrides(
+        mut self,
+        validate_all: bool,
+        lookup: EnvLookup,
+    ) -> Result<Self, ConfigError> {
+        let env_overrides = self.apply_env_overrides(lookup)?;
+        self.validate_startup_limits(&env_overrides)?;
+        self.validate_paths(validate_all, &env_overrides)?;
+        Ok(self)
+    }
+
+    /// Overlay `CYMBIONT_<SECTION>_<FIELD>` environment variables onto the config
+    ///
+    /// Returns the variables that were applied. Set `CYMBIONT_CORPUS_PATH` to an
+    /// empty string to disable document sync.
+    fn apply_env_overrides(&mut self, lookup: EnvLookup) -> Result<EnvOverrides, ConfigError> {
+        let mut env = EnvOverrides::with_lookup(lookup);
+
+        env.apply("CYMBIONT_GRAPHITI_BASE_URL", &mut self.graphiti.base_url)?;
+        env.apply(
+            "CYMBIONT_GRAPHITI_TIMEOUT_SECS",
+            &mut self.graphiti.timeout_secs,
+        )?;
+        env.apply(
+            "CYMBIONT_GRAPHITI_DEFAULT_GROUP_ID",
+            &mut self.graphiti.default_group_id,
+        )?;
+        env.apply(
+            "CYMBIONT_GRAPHITI_SERVER_PATH",
+            &mut self.graphiti.server_path,
+        )?;
+        env.apply(
+            "CYMBIONT_GRAPHITI_STARTUP_TIMEOUT_SECS",
+            &mut self.graphiti.startup_timeout_secs,
+        )?;
+        env.apply(
+            "CYMBIONT_GRAPHITI_MAX_STARTUP_ATTEMPTS",
+            &mut self.graphiti.max_startup_attempts,
+        )?;
+        env.apply(
+            "CYMBIONT_GRAPHITI_KILL_ON_EXIT",
+            &mut self.graphiti.kill_on_exit,
+        )?;
+        env.apply(
+            "CYMBIONT_GRAPHITI_SYNC_RETRY_ATTEMPTS",
+            &mut self.graphiti.sync_retry_attempts,
+        )?;
+
+        env.apply("CYMBIONT_SERVER_READ_ONLY", &mut self.server.read_only)?;
+        env.apply_optional(
+            "CYMBIONT_SERVER_PREVIEW_CHARS",
+            &mut self.server.preview_chars,
+        )?;
+
+        env.apply_optional("CYMBIONT_CORPUS_PATH", &mut self.corpus.path)?;
+        env.apply(
+            "CYMBIONT_CORPUS_SYNC_INTERVAL_HOURS",
+            &mut self.corpus.sync_interval_hours,
+        )?;
+
+        env.apply("CYMBIONT_LOGGING_LEVEL", &mut self.logging.level)?;
+        env.apply(
+            "CYMBIONT_LOGGING_LOG_DIRECTORY",
+            &mut self.logging.log_directory,
+        )?;
+        env.apply("CYMBIONT_LOGGING_MAX_FILES", &mut self.logging.max_files)?;
+        env.apply(
+            "CYMBIONT_LOGGING_MAX_SIZE_MB",
+            &mut self.logging.max_size_mb,
+        )?;
+
+        Ok(env)
     }
 
     /// Search for config.yaml in standard locations
@@ -264,11 +485,76 @@ impl Config {
         Ok(None)
     }
 
+    /// Reject Graphiti startup limits of 0, which would make the post-launch healthcheck
+    /// give up (and kill the server) before it ever polls
+    fn validate_startup_limits(&self, env_overrides: &EnvOverrides) -> Result<(), ConfigError> {
+        let limits = [
+            (
+                "max_startup_attempts",
+                "CYMBIONT_GRAPHITI_MAX_STARTUP_ATTEMPTS",
+                u64::from(self.graphiti.max_startup_attempts),
+            ),
+            (
+                "startup_timeout_secs",
+                "CYMBIONT_GRAPHITI_STARTUP_TIMEOUT_SECS",
+                self.graphiti.startup_timeout_secs,
+            ),
+        ];
+
+        for (field, env_var, value) in limits {
+            if value == 0 {
+                let message = format!("graphiti.{field} must be at least 1");
+                return Err(if env_overrides.contains(env_var) {
+                    ConfigError::Env(format!("{env_var}: {message}"))
+                } else {
+                    ConfigError::Validation(message)
+                });
+            }
+        }
+
+        Ok(())
+    }
+
     /// Validate and normalize paths
     /// - `log_directory`: Can be relative (resolved from binary location) or absolute
     /// - `corpus.path`: Optional; if provided, must be absolute
     /// - `server_path`: Can be relative (resolved from binary location) or absolute
-    fn validate_paths(&mut self) -> Result<(), ConfigError> {
+    ///
+    /// With `validate_all` false (no config file), only fields set from the environment
+    /// are checked. Failures in env-sourced fields are reported as `ConfigError::Env`.
+    fn validate_paths(
+        &mut self,
+        validate_all: bool,
+        env_overrides: &EnvOverrides,
+    ) -> Result<(), ConfigError> {
+        type Check = fn(&mut Config) -> Result<(), ConfigError>;
+        let checks: [(&str, Check); 3] = [
+            (
+                "CYMBIONT_LOGGING_LOG_DIRECTORY",
+                Self::resolve_log_directory,
+            ),
+            ("CYMBIONT_CORPUS_PATH", Self::validate_corpus_path),
+            ("CYMBIONT_GRAPHITI_SERVER_PATH", Self::resolve_server_path),
+        ];
+
+        for (env_var, check) in checks {
+            let from_env = env_overrides.contains(env_var);
+            if !validate_all && !from_env {
+                continue;
+            }
+            check(self).map_err(|e| {
+                if from_env {
+                    ConfigError::Env(format!("{env_var}: {e}"))
+                } else {
+                    e
+                }
+            })?;
+        }
+
+        Ok(())
+    }
+
+    fn resolve_log_directory(&mut self) -> Result<(), ConfigError> {
         // Resolve log_directory relative to binary location if not absolute
         let log_path = Path::new(&self.logging.log_directory);
         if !log_path.is_absolute() {
@@ -284,6 +570,10 @@ impl Config {
             self.logging.log_directory = resolved.to_string_lossy().to_string();
         }
 
+        Ok(())
+    }
+
+    fn validate_corpus_path(&mut self) -> Result<(), ConfigError> {
         // Corpus path is optional; if provided, must be absolute
         if let Some(corpus_path_str) = &self.corpus.path {
             let corpus_path = Path::new(corpus_path_str);
@@ -304,6 +594,10 @@ impl Config {
             }
         }
 
+        Ok(())
+    }
+
+    fn resolve_server_path(&mut self) -> Result<(), ConfigError> {
         // Graphiti server path - resolve relative paths from binary location
         if self.graphiti.server_path.is_empty() {
             return Err(ConfigError::Validation(
@@ -340,3 +634,144 @@ impl Config {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn env_override_parses_value() {
+        let mut overrides = EnvOverrides::with_lookup(|name| {
+            (name == "CYMBIONT_TEST_PARSE_U64").then(|| "42".to_string())
+        });
+        let mut value = 5u64;
+
+        overrides
+            .apply("CYMBIONT_TEST_PARSE_U64", &mut value)
+            .expect("valid value");
+
+        assert_eq!(value, 42);
+        assert!(overrides.contains("CYMBIONT_TEST_PARSE_U64"));
+    }
+
+    #[test]
+    fn env_override_rejects_invalid_value() {
+        let mut overrides = EnvOverrides::with_lookup(|name| {
+            (name == "CYMBIONT_TEST_INVALID_U64").then(|| "abc".to_string())
+        });
+        let mut value = 5u64;
+
+        let result = overrides.apply("CYMBIONT_TEST_INVALID_U64", &mut value);
+
+        assert!(matches!(result, Err(ConfigError::Env(_))));
+        assert_eq!(value, 5);
+    }
+
+    #[test]
+    fn env_override_leaves_unset_value_alone() {
+        let mut overrides = EnvOverrides::with_lookup(|_| None);
+        let mut value = 5u64;
+
+        overrides
+            .apply("CYMBIONT_TEST_UNSET_U64", &mut value)
+            .expect("unset variable");
+
+        assert_eq!(value, 5);
+        assert!(!overrides.contains("CYMBIONT_TEST_UNSET_U64"));
+    }
+
+    #[test]
+    fn env_override_empty_clears_optional() {
+        let mut overrides = EnvOverrides::with_lookup(|name| {
+            (name == "CYMBIONT_TEST_EMPTY_PATH").then(String::new)
+        });
+        let mut value = Some("/srv/corpus".to_string());
+
+        overrides
+            .apply_optional("CYMBIONT_TEST_EMPTY_PATH", &mut value)
+            .expect("empty value");
+
+        assert_eq!(value, None);
+    }
+
+    #[test]
+    fn env_wins_over_yaml() {
+        let config: Config = serde_yaml::from_str("graphiti:\n  base_url: http://yaml-host:8000\n")
+            .expect("valid yaml");
+        assert_eq!(config.graphiti.base_url, "http://yaml-host:8000");
+
+        let config = config
+            .with_env_overrides(false, |name| {
+                (name == "CYMBIONT_GRAPHITI_BASE_URL").then(|| "http://env-host:9000".to_string())
+            })
+            .expect("valid overrides");
+
+        assert_eq!(config.graphiti.base_url, "http://env-host:9000");
+    }
+
+    #[test]
+    fn defaults_fallback_still_applies_env() {
+        let config = Config::default()
+            .with_env_overrides(false, |name| match name {
+                "CYMBIONT_GRAPHITI_BASE_URL" => Some("http://graphiti:8000".to_string()),
+                "CYMBIONT_SERVER_READ_ONLY" => Some("true".to_string()),
+                _ => None,
+            })
+            .expect("valid overrides");
+
+        assert_eq!(config.graphiti.base_url, "http://graphiti:8000");
+        assert!(config.server.read_only);
+
+        let invalid = Config::default().with_env_overrides(false, |name| {
+            (name == "CYMBIONT_GRAPHITI_TIMEOUT_SECS").then(|| "abc".to_string())
+        });
+        assert!(matches!(invalid, Err(ConfigError::Env(_))));
+    }
+
+    #[test]
+    fn zero_startup_limits_are_rejected() {
+        let mut config = Config::default();
+        config
+            .validate_startup_limits(&EnvOverrides::default())
+            .expect("defaults are valid");
+
+        config.graphiti.max_startup_attempts = 0;
+        let from_yaml = config.validate_startup_limits(&EnvOverrides::default());
+        assert!(matches!(from_yaml, Err(ConfigError::Validation(_))));
+
+        let overrides = EnvOverrides {
+            applied: vec!["CYMBIONT_GRAPHITI_MAX_STARTUP_ATTEMPTS"],
+            ..EnvOverrides::with_lookup(|_| None)
+        };
+        let from_env = config.validate_startup_limits(&overrides);
+        assert!(matches!(from_env, Err(ConfigError::Env(_))));
+
+        config.graphiti.max_startup_attempts = 20;
+        config.graphiti.startup_timeout_secs = 0;
+        let zero_timeout = config.validate_startup_limits(&EnvOverrides::default());
+        assert!(matches!(zero_timeout, Err(ConfigError::Validation(_))));
+    }
+
+    #[test]
+    fn defaults_without_config_file_skip_path_validation() {
+        let mut config = Config::default();
+
+        config
+            .validate_paths(false, &EnvOverrides::default())
+            .expect("defaults are not validated");
+    }
+
+    #[test]
+    fn invalid_env_path_is_env_error() {
+        let mut config = Config::default();
+        config.graphiti.server_path = "/nonexistent/graphiti/server".to_string();
+        let overrides = EnvOverrides {
+            applied: vec!["CYMBIONT_GRAPHITI_SERVER_PATH"],
+            ..EnvOverrides::with_lookup(|_| None)
+        };
+
+        let result = config.validate_paths(false, &overrides);
+
+        assert!(matches!(result, Err(ConfigError::Env(_))));
+    }
+}
@@ -5,8 +5,11 @@ use thiserror::Error;
 /// Graphiti HTTP client errors
 #[derive(Error, Debug)]
 pub enum GraphitiError {
-    #[error("HTTP request failed: {0}")]
-    Request(String),
+    #[error("HTTP request failed: HTTP {status}: {body}")]
+    Request {
+        status: reqwest::StatusCode,
+        body: String,
+    },
 
     #[error("Invalid response: {0}")]
     InvalidResponse(String),
@@ -16,6 +19,21 @@ pub enum GraphitiError {
 
     #[error("HTTP error: {0}")]
     Http(#[from] reqwest::Error),
+
+    #[error("Document sync trigger already in progress")]
+    SyncInProgress,
+}
+
+impl GraphitiError {
+    /// Whether retrying could help: the connection failed before the request was sent,
+    /// or Graphiti answered with a 5xx
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::Http(e) => e.is_connect(),
+            Self::Request { status, .. } => status.is_server_error(),
+            Self::InvalidResponse(_) | Self::Serialization(_) | Self::SyncInProgress => false,
+        }
+    }
 }
 
 /// Configuration loading errors
@@ -29,4 +47,8 @@ pub enum ConfigError {
 
     #[error("Validation error: {0}")]
     Validation(String),
+
+    /// Invalid `CYMBIONT_*` environment override - never silently replaced by defaults
+    #[error("Environment override error: {0}")]
+    Env(String),
 }
@@ -1,16 +1,73 @@
 //! Graphiti server launcher - ensures backend is running
 //!
-//! This module manages the Graphiti `FastAPI` server lifecycle to prevent data loss
-//! during episode ingestion. The server is launched as a detached background process
-//! and intentionally left running (resource leak) until system shutdown.
+//! This module manages the Graphiti `FastAPI` server lifecycle. The server is launched
+//! as a background process in its own process group. If this Cymbiont instance launched
+//! it, the returned `GraphitiProcess` handle can terminate it on exit (SIGTERM, then
+//! SIGKILL after a grace period); main.rs skips that while other Cymbiont instances are
+//! still running (see `other_cymbiont_instances_running`). With
+//! `graphiti.kill_on_exit: false` the handle is dropped instead and the server keeps
+//! running until system shutdown, so queued episode ingestion is never interrupted.
 
+use crate::config::GraphitiConfig;
 use anyhow::{Context, Result};
+use nix::sys::signal::{killpg, Signal};
+use nix::unistd::Pid;
 use std::fs::OpenOptions;
 use std::path::Path;
-use std::process::Stdio;
+use std::process::{ExitStatus, Stdio};
 use std::time::Duration;
+use tokio::process::Child;
 use tokio::time::sleep;
 
+/// Handle to a Graphiti server process launched by this Cymbiont instance
+#[derive(Debug)]
+pub struct GraphitiProcess {
+    child: Child,
+}
+
+impl GraphitiProcess {
+    /// Spawn `command` as the leader of a new process group
+    fn spawn(mut command: tokio::process::Command) -> std::io::Result<Self> {
+        let child = command.process_group(0).spawn()?;
+        Ok(Self { child })
+    }
+
+    /// Terminate the Graphiti process group
+    ///
+    /// Sends SIGTERM to the whole process group (`uv` plus the uvicorn worker it spawns)
+    /// and waits up to `grace_period` for a clean exit before falling back to SIGKILL.
+    pub async fn shutdown(mut self, grace_period: Duration) -> Result<()> {
+        let Some(pid) = self.child.id() else {
+            // Already reaped - nothing left to stop
+            return Ok(());
+        };
+
+        #[allow(clippy::cast_possible_wrap)]
+        let process_group = Pid::from_raw(pid as i32);
+        if let Err(e) = killpg(process_group, Signal::SIGTERM) {
+            tracing::warn!("Failed to send SIGTERM to Graphiti (pid {pid}): {e}");
+        }
+
+        if let Ok(status) = tokio::time::timeout(grace_period, self.child.wait()).await {
+            let status = status.context("Failed to wait for Graphiti to exit")?;
+            tracing::info!("Graphiti server stopped ({status})");
+            return Ok(());
+        }
+
+        tracing::warn!(
+            "Graphiti did not exit within {grace_period:?} after SIGTERM, sending SIGKILL"
+        );
+        killpg(process_group, Signal::SIGKILL).context("Failed to send SIGKILL to Graphiti")?;
+
+        self.child
+            .wait()
+            .await
+            .context("Failed to wait for Graphiti to exit")?;
+        tracing::info!("Graphiti server killed");
+        Ok(())
+    }
+}
+
 /// Check if Graphiti is already running by hitting the health endpoint
 pub async fn is_graphiti_running(base_url: &str) -> bool {
     let health_url = format!("{base_url}/healthcheck");
@@ -25,9 +82,10 @@ pub async fn is_graphiti_running(base_url: &str) -> bool {
         .unwrap_or(false)
 }
 
-/// Launch Graphiti as detached background process
+/// Launch Graphiti as background process
 ///
 /// The process is spawned with:
+/// - Its own process group (so shutdown signals reach uvicorn, not just `uv`)
 /// - stdin redirected to null
 /// - stdout and stderr redirected to `log_path` (truncate mode - fresh log per launch)
 /// - Working directory set to `server_path`
@@ -36,9 +94,9 @@ pub async fn is_graphiti_running(base_url: &str) -> bool {
 /// Both stdout and stderr are redirected to the same log file using `File::try_clone()`
 /// to ensure proper interleaving of output (equivalent to shell's `> log 2>&1`).
 ///
-/// Note: This is an intentional "resource leak" - the process will continue
-/// running after Cymbiont exits, ensuring no data loss during episode ingestion.
-pub fn launch_graphiti(server_path: &str, log_path: &Path) -> Result<()> {
+/// The returned handle does not kill the process on drop - dropping it leaves Graphiti
+/// running after Cymbiont exits.
+pub fn launch_graphiti(server_path: &str, log_path: &Path) -> Result<GraphitiProcess> {
     tracing::info!("Graphiti not running, launching background server...");
 
     // Ensure log directory exists
@@ -60,82 +118,365 @@ pub fn launch_graphiti(server_path: &str, log_path: &Path) -> Result<()> {
         .try_clone()
         .context("Failed to clone log file handle for stderr")?;
 
-    // Spawn fully detached process using uv run to manage dependencies
-    tokio::process::Command::new("uv")
+    // Spawn background process using uv run to manage dependencies
+    let mut command = tokio::process::Command::new("uv");
+    command
         .arg("run")
         .arg("uvicorn")
         .arg("graph_service.main:app")
         .current_dir(server_path)
         .stdin(Stdio::null())
         .stdout(Stdio::from(log_file))
-        .stderr(Stdio::from(log_file_stderr))
-        .spawn()
-        .context("Failed to spawn Graphiti server")?;
+        .stderr(Stdio::from(log_file_stderr));
+
+    let process = GraphitiProcess::spawn(command).context("Failed to spawn Graphiti server")?;
 
     tracing::info!(
         "Graphiti server spawned, logging to: {}",
         log_path.display()
     );
-    Ok(())
+    Ok(process)
+}
+
+/// Whether another Cymbiont process (same executable) is alive, based on `/proc/*/exe`
+///
+/// Other instances - e.g. the headless session `hooks/monitoring_worker.py` starts to
+/// call `add_memory` - may still be writing to Graphiti. Returns false where `/proc` is
+/// unavailable.
+pub fn other_cymbiont_instances_running() -> bool {
+    let Ok(own_exe) = std::env::current_exe() else {
+        return false;
+    };
+    let Ok(entries) = std::fs::read_dir("/proc") else {
+        return false;
+    };
+    let own_pid = std::process::id();
+
+    entries.flatten().any(|entry| {
+        let is_other_pid = entry
+            .file_name()
+            .to_str()
+            .and_then(|name| name.parse::<u32>().ok())
+            .is_some_and(|pid| pid != own_pid);
+
+        // Processes of other users are unreadable and skipped
+        is_other_pid
+            && std::fs::read_link(entry.path().join("exe"))
+                .is_ok_and(|exe| same_executable(&exe, &own_exe))
+    })
+}
+
+/// Compare executable paths, ignoring the " (deleted)" suffix `/proc` adds after a rebuild
+fn same_executable(a: &Path, b: &Path) -> bool {
+    let strip = |path: &Path| {
+        let path = path.to_string_lossy();
+        path.strip_suffix(" (deleted)").unwrap_or(&path).to_string()
+    };
+    strip(a) == strip(b)
+}
+
+/// Initial delay between healthcheck polls (doubles after each failed attempt)
+const INITIAL_POLL_DELAY: Duration = Duration::from_millis(250);
+
+/// Upper bound on the delay between healthcheck polls
+const MAX_POLL_DELAY: Duration = Duration::from_secs(4);
+
+/// How long to keep polling before launching, in case another instance is mid-launch
+/// (covers the ~7s `uv` + Python cold start)
+const PRE_LAUNCH_WAIT: Duration = Duration::from_secs(10);
+
+/// Time to wait after SIGTERM before escalating to SIGKILL
+pub const SHUTDOWN_GRACE_PERIOD: Duration = Duration::from_secs(5);
+
+/// Outcome of `ensure_graphiti_running`
+#[derive(Debug)]
+pub enum GraphitiStatus {
+    /// An existing Graphiti instance answered the healthcheck (no process spawned)
+    AlreadyRunning,
+    /// Graphiti was launched by this Cymbiont instance
+    Launched(GraphitiProcess),
+}
+
+impl GraphitiStatus {
+    /// Process handle, if this instance launched Graphiti
+    pub fn into_process(self) -> Option<GraphitiProcess> {
+        match self {
+            Self::AlreadyRunning => None,
+            Self::Launched(process) => Some(process),
+        }
+    }
 }
 
-/// Wait for Graphiti to become healthy with fixed interval polling
+/// Why healthcheck polling stopped
+enum PollOutcome {
+    /// Graphiti answered the healthcheck
+    Healthy,
+    /// The watched child process exited before Graphiti became healthy
+    ChildExited(ExitStatus),
+}
+
+/// Wait for Graphiti to become healthy with exponential backoff polling
+///
+/// Polls up to `max_attempts` times, starting at 250ms between attempts and doubling
+/// up to a 4s cap. Gives up once `timeout` has elapsed (the last sleep is shortened so
+/// the final poll lands on the deadline).
+pub async fn wait_for_graphiti(base_url: &str, max_attempts: u32, timeout: Duration) -> Result<()> {
+    poll_until_healthy(base_url, max_attempts, timeout, None)
+        .await
+        .map(|_| ())
+}
+
+/// Backoff polling shared by `wait_for_graphiti` and the post-launch wait
 ///
-/// Attempts health checks with 500ms intervals up to `max_attempts` times.
-pub async fn wait_for_graphiti(base_url: &str, max_attempts: u32) -> Result<()> {
+/// With `child` set, each attempt first checks whether that process has exited and stops
+/// early if so, rather than waiting out the remaining attempts.
+async fn poll_until_healthy(
+    base_url: &str,
+    max_attempts: u32,
+    timeout: Duration,
+    mut child: Option<&mut Child>,
+) -> Result<PollOutcome> {
     let start = std::time::Instant::now();
+    let mut delay = INITIAL_POLL_DELAY;
 
     for attempt in 1..=max_attempts {
-        tracing::info!(
+        tracing::trace!(
             "Healthcheck attempt {attempt}/{max_attempts} (elapsed: {:?})",
             start.elapsed()
         );
 
+        if let Some(child) = child.as_deref_mut() {
+            if let Some(status) = child
+                .try_wait()
+                .context("Failed to check Graphiti process status")?
+            {
+                return Ok(PollOutcome::ChildExited(status));
+            }
+        }
+
         if is_graphiti_running(base_url).await {
             tracing::info!("Graphiti server is healthy after {:?}", start.elapsed());
-            return Ok(());
+            return Ok(PollOutcome::Healthy);
         }
 
-        if attempt < max_attempts {
-            sleep(Duration::from_millis(500)).await;
+        let remaining = timeout.saturating_sub(start.elapsed());
+        if attempt == max_attempts || remaining.is_zero() {
+            break;
         }
+
+        sleep(delay.min(remaining)).await;
+        delay = (delay * 2).min(MAX_POLL_DELAY);
     }
 
     anyhow::bail!(
-        "Graphiti failed to start after {max_attempts} attempts ({:?} elapsed)",
+        "Graphiti failed to become healthy at {base_url} ({:?} elapsed, limits: {max_attempts} attempts / {timeout:?})",
         start.elapsed()
     )
 }
 
 /// Ensure Graphiti is running, launch if needed
 ///
-/// This function checks if Graphiti is already running at `base_url`.
-/// If not, it launches the server from `server_path` and waits for it to become healthy.
-/// If already running, it simply logs and continues.
+/// Performs a single healthcheck first; if an instance already answers, nothing is
+/// spawned. Otherwise it keeps polling for up to 10s in case another Cymbiont instance is
+/// mid-launch, and only then launches the server from `server_path` and polls until
+/// healthy.
 ///
 /// # Arguments
-/// * `base_url` - The base URL where Graphiti should be running (e.g., <http://localhost:8000>)
-/// * `server_path` - Path to `graphiti-cymbiont/server` directory
+/// * `config` - Graphiti config (`base_url`, `server_path`, startup polling limits)
 /// * `log_path` - Path to log file for stdout/stderr redirection
 ///
 /// # Returns
-/// * `Ok(())` if Graphiti is running (either already or after launch)
-/// * `Err` if unable to launch or server doesn't become healthy within timeout
+/// * `Ok(GraphitiStatus)` telling whether Graphiti was already running or launched by us
+/// * `Err` if unable to launch or server doesn't become healthy within the configured limits
 pub async fn ensure_graphiti_running(
-    base_url: &str,
-    server_path: &str,
+    config: &GraphitiConfig,
     log_path: &Path,
-) -> Result<()> {
-    // Try waiting for Graphiti first (maybe it's starting up)
-    // Increased to 20 attempts to account for Python environment cold start (~7s)
-    if wait_for_graphiti(base_url, 20).await.is_ok() {
+) -> Result<GraphitiStatus> {
+    let base_url = &config.base_url;
+
+    if is_graphiti_running(base_url).await {
         tracing::info!("Graphiti already running");
-        return Ok(());
+        return Ok(GraphitiStatus::AlreadyRunning);
+    }
+
+    // Another instance may be starting it right now - give it the length of a cold start
+    // before spawning, so we don't end up with two servers fighting over the port
+    if wait_for_graphiti(base_url, u32::MAX, PRE_LAUNCH_WAIT)
+        .await
+        .is_ok()
+    {
+        tracing::info!("Graphiti finished starting up (launched elsewhere)");
+        return Ok(GraphitiStatus::AlreadyRunning);
     }
 
     // Still not running - launch it
-    launch_graphiti(server_path, log_path)?;
-    wait_for_graphiti(base_url, 20).await?; // 20 attempts * 500ms = 10s max (accounts for uv + Python startup)
+    let process = launch_graphiti(&config.server_path, log_path)?;
+    wait_for_launched_graphiti(process, config).await
+}
+
+/// Poll a server we just launched until it is healthy
+///
+/// If our process exits first (typically because another instance won the race for the
+/// port), ownership is never claimed: a healthy server elsewhere yields `AlreadyRunning`,
+/// otherwise startup fails right away instead of waiting out the timeout.
+async fn wait_for_launched_graphiti(
+    mut process: GraphitiProcess,
+    config: &GraphitiConfig,
+) -> Result<GraphitiStatus> {
+    let base_url = &config.base_url;
+    let timeout = Duration::from_secs(config.startup_timeout_secs);
+
+    match poll_until_healthy(
+        base_url,
+        config.max_startup_attempts,
+        timeout,
+        Some(&mut process.child),
+    )
+    .await
+    {
+        Ok(PollOutcome::Healthy) => Ok(GraphitiStatus::Launched(process)),
+        Ok(PollOutcome::ChildExited(status)) => {
+            if is_graphiti_running(base_url).await {
+                tracing::info!(
+                    "Launched Graphiti exited ({status}) but another instance is serving {base_url}"
+                );
+                Ok(GraphitiStatus::AlreadyRunning)
+            } else {
+                anyhow::bail!("Graphiti server exited during startup ({status})")
+            }
+        }
+        Err(e) => {
+            // Don't leave a half-started server behind if we're about to bail out
+            if config.kill_on_exit {
+                if let Err(shutdown_err) = process.shutdown(SHUTDOWN_GRACE_PERIOD).await {
+                    tracing::error!("Failed to stop unhealthy Graphiti server: {shutdown_err}");
+                }
+            }
+            Err(e)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::MockGraphiti;
+
+    /// Spawn `script` under `sh` through the same process-group path as Graphiti
+    ///
+    /// Scripts `exec` their workload so the group is a single process we reap ourselves
+    fn spawn_dummy(script: &str) -> (GraphitiProcess, Pid) {
+        let mut command = tokio::process::Command::new("sh");
+        command.arg("-c").arg(script);
+        let process = GraphitiProcess::spawn(command).expect("spawn dummy child");
+        #[allow(clippy::cast_possible_wrap)]
+        let pid = Pid::from_raw(process.child.id().expect("running child") as i32);
+        (process, pid)
+    }
+
+    /// Signal 0 probes whether any process is left in the group
+    fn group_alive(pid: Pid) -> bool {
+        killpg(pid, None).is_ok()
+    }
+
+    #[tokio::test]
+    async fn shutdown_terminates_long_running_child() {
+        let (process, pid) = spawn_dummy("exec sleep 60");
+        assert!(group_alive(pid));
+
+        process
+            .shutdown(Duration::from_secs(5))
+            .await
+            .expect("shutdown succeeds");
+        assert!(!group_alive(pid));
+    }
+
+    #[tokio::test]
+    async fn shutdown_escalates_to_sigkill_when_sigterm_is_ignored() {
+        // Ignored signals stay ignored across exec, so `sleep` ignores SIGTERM too
+        let (process, pid) = spawn_dummy("trap '' TERM; exec sleep 60");
+        tokio::time::sleep(Duration::from_millis(100)).await; // let the trap install
+
+        let start = std::time::Instant::now();
+        process
+            .shutdown(Duration::from_millis(300))
+            .await
+            .expect("shutdown succeeds");
+        assert!(start.elapsed() >= Duration::from_millis(300));
 
-    Ok(())
+        assert!(!group_alive(pid));
+    }
+
+    #[tokio::test]
+    async fn wait_for_graphiti_polls_until_healthy() {
+        let mock = MockGraphiti::start(vec![(503, ""), (503, ""), (200, "{}")]).await;
+
+        wait_for_graphiti(&mock.base_url, 5, Duration::from_secs(10))
+            .await
+            .expect("healthy on third poll");
+        assert_eq!(mock.requests(), 3);
+    }
+
+    #[tokio::test]
+    async fn wait_for_graphiti_gives_up_after_max_attempts() {
+        let mock = MockGraphiti::start(vec![(503, "")]).await;
+
+        let result = wait_for_graphiti(&mock.base_url, 3, Duration::from_secs(10)).await;
+        assert!(result.is_err());
+        assert_eq!(mock.requests(), 3);
+    }
+
+    /// Config pointing at `base_url` with a short startup timeout
+    fn test_config(base_url: &str) -> GraphitiConfig {
+        GraphitiConfig {
+            base_url: base_url.to_string(),
+            startup_timeout_secs: 10,
+            ..GraphitiConfig::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn launched_child_exit_fails_fast_when_nothing_is_healthy() {
+        // Grab a free port with nothing listening on it
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind");
+        let base_url = format!("http://{}", listener.local_addr().expect("local addr"));
+        drop(listener);
+
+        let (process, _) = spawn_dummy("exit 3");
+        let start = std::time::Instant::now();
+
+        let result = wait_for_launched_graphiti(process, &test_config(&base_url)).await;
+
+        assert!(result.is_err());
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn launched_child_exit_defers_to_other_healthy_instance() {
+        let mock = MockGraphiti::start(vec![(200, "{}")]).await;
+        let (process, _) = spawn_dummy("exit 1");
+        tokio::time::sleep(Duration::from_millis(200)).await; // let the child exit
+
+        let status = wait_for_launched_graphiti(process, &test_config(&mock.base_url))
+            .await
+            .expect("other instance is reused");
+
+        assert!(matches!(status, GraphitiStatus::AlreadyRunning));
+    }
+
+    #[tokio::test]
+    async fn ensure_graphiti_running_does_not_launch_when_already_healthy() {
+        let mock = MockGraphiti::start(vec![(200, "{}")]).await;
+        let config = GraphitiConfig {
+            base_url: mock.base_url.clone(),
+            server_path: "/nonexistent/graphiti".to_string(),
+            ..GraphitiConfig::default()
+        };
+
+        let status = ensure_graphiti_running(&config, Path::new("/nonexistent/graphiti.log"))
+            .await
+            .expect("existing instance is reused");
+        assert!(matches!(status, GraphitiStatus::AlreadyRunning));
+        assert_eq!(mock.requests(), 1);
+    }
 }
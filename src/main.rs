@@ -5,22 +5,32 @@ mod config;
 mod error;
 mod graphiti_launcher;
 mod mcp_tools;
+#[cfg(test)]
+mod test_support;
 mod types;
 
 use client::GraphitiClient;
 use config::Config;
+use error::ConfigError;
 use mcp_tools::CymbiontService;
 use rmcp::ServiceExt;
 use std::path::PathBuf;
 use tokio::io::{stdin, stdout};
+use tokio::signal::unix::{signal, SignalKind};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Load configuration
-    let config = Config::load().unwrap_or_else(|_| {
-        eprintln!("Warning: Failed to load config.yaml, using defaults");
-        Config::default()
-    });
+    // A bad CYMBIONT_* override is fatal; running with defaults would silently ignore it.
+    // If config.yaml itself is unusable, fall back to defaults but keep env overrides.
+    let (config, config_load_error) = match Config::load() {
+        Ok(config) => (config, None),
+        Err(e @ ConfigError::Env(_)) => return Err(e.into()),
+        Err(e) => {
+            eprintln!("Warning: Failed to load config.yaml ({e}), using defaults");
+            (Config::from_defaults_with_env()?, Some(e))
+        }
+    };
 
     // Initialize file-only logging with verbosity monitoring (CRITICAL for MCP mode)
     let verbosity_config = autodebugger::VerbosityConfig {
@@ -49,72 +59,90 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         "Cymbiont MCP server starting (version {})",
         env!("CARGO_PKG_VERSION")
     );
+    if let Some(e) = config_load_error {
+        tracing::warn!("Failed to load config.yaml, using defaults with env overrides: {e}");
+    }
 
     // Construct Graphiti log path (in same directory as Cymbiont logs)
     let graphiti_log_path =
         PathBuf::from(&config.logging.log_directory).join("graphiti_latest.log");
 
-    // Ensure Graphiti backend is running (launch if needed, intentional resource leak)
-    graphiti_launcher::ensure_graphiti_running(
-        &config.graphiti.base_url,
-        &config.graphiti.server_path,
-        &graphiti_log_path,
-    )
-    .await?;
-
-    // Create Graphiti HTTP client
+    // Create Graphiti HTTP client (before launching, so nothing below can bail out early
+    // and leak a process we own)
     let client = GraphitiClient::new(&config.graphiti)?;
     tracing::info!(
         "Graphiti client initialized (base_url: {})",
         config.graphiti.base_url
     );
 
-    // Initialize document sync if corpus path is configured
-    let sync_enabled = if let Some(corpus_path) = &config.corpus.path {
+    // Ensure Graphiti backend is running (launch if needed)
+    let graphiti_status =
+        graphiti_launcher::ensure_graphiti_running(&config.graphiti, &graphiti_log_path).await?;
+    tracing::trace!("Graphiti backend status: {:?}", graphiti_status);
+
+    // Keep the process handle only if we own its lifetime; otherwise it is left running
+    let graphiti_process = graphiti_status
+        .into_process()
+        .filter(|_| config.graphiti.kill_on_exit);
+
+    // Initialize document sync if corpus path is configured (sync writes to the graph,
+    // so it stays off in read-only mode). Runs in the background so retries against a
+    // slow backend don't hold up MCP startup.
+    let startup_sync = if config.server.read_only {
+        tracing::info!("Read-only mode - document sync and write tools disabled");
+        None
+    } else if let Some(corpus_path) = config.corpus.path.clone() {
         tracing::info!("Corpus path configured: {}", corpus_path);
 
-        // Start document sync watcher (hourly sync)
-        match client
-            .start_sync(
-                corpus_path,
-                config.corpus.sync_interval_hours,
-                &config.graphiti.default_group_id,
-            )
-            .await
-        {
-            Ok(msg) => tracing::info!("Document sync watcher started: {}", msg),
-            Err(e) => tracing::error!(
-                "Failed to start document sync watcher: {} (continuing without sync)",
-                e
-            ),
-        }
-
-        // Trigger immediate sync on startup
-        match client.trigger_sync().await {
-            Ok(msg) => tracing::info!("Initial document sync triggered: {}", msg),
-            Err(e) => tracing::error!("Failed to trigger initial sync: {}", e),
-        }
-
-        true
+        let client = client.clone();
+        let sync_interval_hours = config.corpus.sync_interval_hours;
+        let group_id = config.graphiti.default_group_id.clone();
+
+        Some(tokio::spawn(async move {
+            // Start document sync watcher (hourly sync)
+            match client
+                .start_sync(&corpus_path, sync_interval_hours, &group_id)
+                .await
+            {
+                Ok(msg) => tracing::info!("Document sync watcher started: {}", msg),
+                Err(e) => tracing::error!(
+                    "Failed to start document sync watcher: {} (continuing without sync)",
+                    e
+                ),
+            }
+
+            // Trigger immediate sync on startup
+            match client.trigger_sync().await {
+                Ok(msg) => tracing::info!("Initial document sync triggered: {}", msg),
+                Err(e) => tracing::error!("Failed to trigger initial sync: {}", e),
+            }
+        }))
     } else {
         tracing::warn!("No corpus path configured - document sync disabled");
         tracing::warn!("To enable document sync, set 'corpus.path' in config.yaml");
-        false
+        None
     };
 
     // Create Cymbiont MCP service
     let service = CymbiontService::new(client.clone(), config);
 
-    // Start MCP server with stdio transport
-    tracing::info!("Starting MCP server with stdio transport");
-    let transport = (stdin(), stdout());
-    let server = service.serve(transport).await?;
-
-    // Wait for server shutdown
-    let _quit_reason = server.waiting().await?;
+    // Serve until the client disconnects, the server fails, or we get SIGINT/SIGTERM.
+    // The result is returned only after cleanup, so Graphiti is stopped on every path.
+    let serve_result = tokio::select! {
+        result = serve(service) => result,
+        () = shutdown_signal() => {
+            tracing::info!("Received shutdown signal");
+            Ok(())
+        }
+    };
+    if let Err(e) = &serve_result {
+        tracing::error!("MCP server error: {}", e);
+    }
 
-    // Graceful shutdown: stop document sync if it was started
-    if sync_enabled {
+    // Graceful shutdown: stop document sync if it was started (abort any startup calls
+    // still retrying first, so they can't restart the watcher afterwards)
+    if let Some(startup_sync) = startup_sync {
+        startup_sync.abort();
         tracing::info!("Shutting down document sync...");
         match client.stop_sync().await {
             Ok(msg) => tracing::info!("{}", msg),
@@ -122,10 +150,62 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
+    // Stop Graphiti if this instance launched it (after sync shutdown, which needs the backend),
+    // unless other instances - e.g. the monitoring worker's session - are still using it
+    if let Some(process) = graphiti_process {
+        if graphiti_launcher::other_cymbiont_instances_running() {
+            tracing::info!("Other Cymbiont instances still running - leaving Graphiti up");
+        } else {
+            tracing::info!("Shutting down Graphiti server...");
+            if let Err(e) = process
+                .shutdown(graphiti_launcher::SHUTDOWN_GRACE_PERIOD)
+                .await
+            {
+                tracing::error!("Failed to stop Graphiti server: {}", e);
+            }
+        }
+    }
+
     // Check for excessive logging and report
     if let Some(report) = verbosity_layer.check_and_report() {
         tracing::warn!("{}", report);
     }
 
+    serve_result
+}
+
+/// Run the MCP server over stdio until the client disconnects
+async fn serve(service: CymbiontService) -> Result<(), Box<dyn std::error::Error>> {
+    tracing::info!("Starting MCP server with stdio transport");
+    let transport = (stdin(), stdout());
+    let server = service.serve(transport).await?;
+
+    let _quit_reason = server.waiting().await?;
     Ok(())
 }
+
+/// Resolve on SIGINT or SIGTERM (never resolves if no handler could be installed)
+async fn shutdown_signal() {
+    let sigterm = async {
+        match signal(SignalKind::terminate()) {
+            Ok(mut stream) => {
+                stream.recv().await;
+            }
+            Err(e) => {
+                tracing::error!("Failed to install SIGTERM handler: {}", e);
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+    let sigint = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            tracing::error!("Failed to install SIGINT handler: {}", e);
+            std::future::pending::<()>().await;
+        }
+    };
+
+    tokio::select! {
+        () = sigterm => {}
+        () = sigint => {}
+    }
+}
@@ -14,6 +14,7 @@
 //!
 //! - **`get_episodes`**: Retrieve recent memory episodes chronologically
 //!   - Returns last N episodes (default 10)
+//!   - Optional `preview_chars` truncates episode content (default: `server.preview_chars`)
 //!   - Use for: Reviewing recent memories, debugging
 //!
 //! - **`delete_episode`**: Remove episode by UUID
@@ -27,17 +28,22 @@
 //!   - Returns 5 nodes + 10 facts (default)
 //!   - Use for: Conceptual exploration, relationship discovery
 //!   - Note: Returns compressed summaries, not exact text
+//!   - Optional `preview_chars` truncates node summaries and fact text
 //!
 //! - **`get_chunks`**: BM25 keyword search over raw document chunks
 //!   - Optional cross-encoder semantic reranking
 //!   - Returns chunks with document URI and position
+//!   - Optional `preview_chars` truncates chunk content
 //!   - Use for: Exact wording, technical precision, source verification
 //!
 //! ## Document Sync
 //!
 //! - **`sync_documents`**: Trigger manual document synchronization
 //!   - Syncs all markdown files in corpus directory
-//!   - Runs in background, returns immediately
+//!   - Runs in background, returns once Graphiti accepts the trigger
+//!   - Rejected while another trigger request (including the startup trigger) is in
+//!     flight; Graphiti doesn't report sync progress, so a background sync that is
+//!     already running is not detected
 //!   - Use for: Forcing immediate sync after adding documents
 //!
 //! # Dual Retrieval Strategy
@@ -59,10 +65,15 @@
 //! - All tools return JSON-formatted strings for Claude Code
 //! - Errors are formatted as user-readable error messages
 //! - No authentication (single-user deployment model)
+//! - With `server.read_only`, `add_memory`, `delete_episode` and `sync_documents` return
+//!   an error; `get_episodes`, `search_context` and `get_chunks` are unaffected
+//! - `preview_chars` (per request, else `server.preview_chars`) truncates long text fields
+//!   by character count, never splitting a multibyte character
 //! - Graphiti backend must be running (auto-launched by main.rs)
 
 use crate::client::GraphitiClient;
 use crate::config::Config;
+use crate::error::GraphitiError;
 use crate::types::{
     AddMemoryRequest, DeleteEpisodeRequest, GetChunksRequest, GetEpisodesRequest,
     SearchContextRequest, SyncDocumentsRequest,
@@ -73,21 +84,57 @@ use rmcp::{
     tool, tool_handler, tool_router, ServerHandler,
 };
 
+/// Error returned by mutating tools when `server.read_only` is set
+const READ_ONLY_ERROR: &str =
+    "Cymbiont is in read-only mode (server.read_only) - writes are disabled";
+
 /// Cymbiont MCP service
 #[derive(Clone)]
 pub struct CymbiontService {
     client: GraphitiClient,
+    read_only: bool,
+    preview_chars: Option<usize>,
     tool_router: ToolRouter<Self>,
 }
 
 impl CymbiontService {
     /// Create new service
-    pub fn new(client: GraphitiClient, _config: Config) -> Self {
+    pub fn new(client: GraphitiClient, config: Config) -> Self {
         Self {
             client,
+            read_only: config.server.read_only,
+            preview_chars: config.server.preview_chars,
             tool_router: Self::tool_router(),
         }
     }
+
+    /// Reject the call if the server is in read-only mode
+    fn ensure_writable(&self) -> Result<(), String> {
+        if self.read_only {
+            return Err(READ_ONLY_ERROR.to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Truncate `text` to at most `max_chars` characters (not bytes), appending an ellipsis
+/// if cut
+fn truncate_preview(text: &str, max_chars: usize) -> Option<String> {
+    text.char_indices()
+        .nth(max_chars)
+        .map(|(byte_idx, _)| format!("{}…", &text[..byte_idx]))
+}
+
+/// Truncate string `field` of every object in a JSON array (non-arrays are left untouched)
+fn truncate_field(items: &mut serde_json::Value, field: &str, max_chars: usize) {
+    for item in items.as_array_mut().into_iter().flatten() {
+        let preview = item[field]
+            .as_str()
+            .and_then(|text| truncate_preview(text, max_chars));
+        if let Some(preview) = preview {
+            item[field] = serde_json::Value::String(preview);
+        }
+    }
 }
 
 #[tool_router]
@@ -98,6 +145,7 @@ impl CymbiontService {
         description = "Add new memory episode to knowledge graph"
     )]
     async fn add_memory(&self, params: Parameters<AddMemoryRequest>) -> Result<String, String> {
+        self.ensure_writable()?;
         let req = &params.0;
 
         self.client
@@ -119,24 +167,27 @@ impl CymbiontService {
         let req = &params.0;
         let last_n = req.last_n.unwrap_or(10).min(100);
 
-        let episodes = self
+        let mut episodes = self
             .client
             .get_episodes("default", Some(last_n))
             .await
             .map_err(|e| format!("Graphiti request failed: {e}"))?;
 
+        // Episode bodies can be long documents - optionally return previews only
+        if let Some(max_chars) = req.preview_chars.or(self.preview_chars) {
+            truncate_field(&mut episodes, "content", max_chars);
+        }
+
         Ok(serde_json::to_string_pretty(&episodes).unwrap_or_default())
     }
 
     /// Delete episode by UUID
-    #[tool(
-        name = "delete_episode",
-        description = "Delete episode by UUID"
-    )]
+    #[tool(name = "delete_episode", description = "Delete episode by UUID")]
     async fn delete_episode(
         &self,
         params: Parameters<DeleteEpisodeRequest>,
     ) -> Result<String, String> {
+        self.ensure_writable()?;
         let req = &params.0;
         self.client
             .delete_episode(&req.uuid)
@@ -151,16 +202,18 @@ impl CymbiontService {
     /// Trigger manual document synchronization
     #[tool(
         name = "sync_documents",
-        description = "Trigger manual document sync for corpus files"
+        description = "Trigger manual document sync for corpus files (returns once Graphiti accepts it; the sync runs in the background and its progress isn't tracked, so a sync started earlier may still be running)"
     )]
     async fn sync_documents(
         &self,
         _params: Parameters<SyncDocumentsRequest>,
     ) -> Result<String, String> {
-        self.client
-            .trigger_sync()
-            .await
-            .map_err(|e| format!("Graphiti request failed: {e}"))
+        self.ensure_writable()?;
+
+        self.client.trigger_sync().await.map_err(|e| match e {
+            GraphitiError::SyncInProgress => e.to_string(),
+            e => format!("Graphiti request failed: {e}"),
+        })
     }
 
     /// Search for both nodes and facts in parallel
@@ -188,11 +241,19 @@ impl CymbiontService {
         let facts = facts_result.map_err(|e| format!("Fact search failed: {e}"))?;
 
         // Merge results into combined JSON
-        let combined = serde_json::json!({
+        let mut combined = serde_json::json!({
             "nodes": nodes["nodes"],
             "facts": facts["facts"]
         });
 
+        if let Some(max_chars) = req.preview_chars.or(self.preview_chars) {
+            for (key, field) in [("nodes", "summary"), ("facts", "fact")] {
+                if let Some(items) = combined.get_mut(key) {
+                    truncate_field(items, field, max_chars);
+                }
+            }
+        }
+
         Ok(serde_json::to_string_pretty(&combined).unwrap_or_default())
     }
 
@@ -205,7 +266,7 @@ impl CymbiontService {
         let req = &params.0;
         let max_results = req.max_results.unwrap_or(10).min(100);
 
-        let response = self
+        let mut response = self
             .client
             .search_chunks(
                 &req.keyword_query,
@@ -215,6 +276,13 @@ impl CymbiontService {
             .await
             .map_err(|e| format!("Chunk search failed: {e}"))?;
 
+        if let Some(max_chars) = req.preview_chars.or(self.preview_chars) {
+            // `get_mut`, not `IndexMut`, which would panic on a non-object response
+            if let Some(chunks) = response.get_mut("chunks") {
+                truncate_field(chunks, "content", max_chars);
+            }
+        }
+
         Ok(serde_json::to_string_pretty(&response["chunks"]).unwrap_or_default())
     }
 }
@@ -239,3 +307,144 @@ impl ServerHandler for CymbiontService {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::MockGraphiti;
+    use std::time::Duration;
+
+    fn test_service(base_url: &str, read_only: bool) -> CymbiontService {
+        let mut config = Config::default();
+        config.graphiti.base_url = base_url.to_string();
+        config.server.read_only = read_only;
+
+        let client = GraphitiClient::new(&config.graphiti).expect("build client");
+        CymbiontService::new(client, config)
+    }
+
+    #[tokio::test]
+    async fn read_only_rejects_writes() {
+        let mock = MockGraphiti::start(vec![(200, "{}")]).await;
+        let service = test_service(&mock.base_url, true);
+
+        let add = service
+            .add_memory(Parameters(AddMemoryRequest {
+                name: "note".to_string(),
+                episode_body: "body".to_string(),
+                source_description: None,
+            }))
+            .await;
+        let delete = service
+            .delete_episode(Parameters(DeleteEpisodeRequest {
+                uuid: "episode-uuid".to_string(),
+            }))
+            .await;
+        let sync = service
+            .sync_documents(Parameters(SyncDocumentsRequest {}))
+            .await;
+
+        for result in [add, delete, sync] {
+            assert_eq!(result, Err(READ_ONLY_ERROR.to_string()));
+        }
+        assert_eq!(mock.requests(), 0);
+    }
+
+    #[tokio::test]
+    async fn read_only_allows_queries() {
+        let mock = MockGraphiti::start(vec![(200, "[]")]).await;
+        let service = test_service(&mock.base_url, true);
+
+        let episodes = service
+            .get_episodes(Parameters(GetEpisodesRequest {
+                last_n: Some(5),
+                preview_chars: None,
+            }))
+            .await;
+
+        assert_eq!(episodes.as_deref(), Ok("[]"));
+        assert_eq!(mock.requests(), 1);
+    }
+
+    #[tokio::test]
+    async fn sync_documents_rejected_while_startup_trigger_in_flight() {
+        let mock =
+            MockGraphiti::start_with_delay(vec![(200, "{}")], Duration::from_millis(300)).await;
+        let service = test_service(&mock.base_url, false);
+
+        // main.rs fires the startup trigger on a clone of the service's client
+        let startup_trigger = tokio::spawn({
+            let client = service.client.clone();
+            async move { client.trigger_sync().await }
+        });
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while mock.requests() == 0 {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("startup trigger never reached the backend");
+
+        let result = service
+            .sync_documents(Parameters(SyncDocumentsRequest {}))
+            .await;
+        assert_eq!(
+            result,
+            Err("Document sync trigger already in progress".to_string())
+        );
+
+        startup_trigger
+            .await
+            .expect("startup trigger task")
+            .expect("startup trigger succeeds");
+        let retry = service
+            .sync_documents(Parameters(SyncDocumentsRequest {}))
+            .await;
+        assert!(retry.is_ok(), "expected success, got {retry:?}");
+        assert_eq!(mock.requests(), 2);
+    }
+
+    #[tokio::test]
+    async fn get_chunks_preview_tolerates_non_object_response() {
+        let mock = MockGraphiti::start(vec![(200, r#"["unexpected"]"#)]).await;
+        let service = test_service(&mock.base_url, false);
+
+        let chunks = service
+            .get_chunks(Parameters(GetChunksRequest {
+                keyword_query: "query".to_string(),
+                max_results: None,
+                rerank_query: None,
+                preview_chars: Some(10),
+            }))
+            .await;
+
+        assert_eq!(chunks.as_deref(), Ok("null"));
+    }
+
+    #[test]
+    fn truncate_preview_counts_chars_not_bytes() {
+        // Multibyte UTF-8 text: a cut by byte count could land inside a character
+        let text = "héllo wörld 日本語";
+
+        assert_eq!(truncate_preview(text, 3).as_deref(), Some("hél…"));
+        assert_eq!(
+            truncate_preview("日本語テキスト", 2).as_deref(),
+            Some("日本…")
+        );
+        assert_eq!(truncate_preview("日本語", 3), None);
+    }
+
+    #[test]
+    fn truncate_field_only_touches_named_field() {
+        let mut chunks = serde_json::json!([
+            {"content": "ünïcödé content", "uri": "notes/long-document-name.md"},
+            {"content": "short"}
+        ]);
+
+        truncate_field(&mut chunks, "content", 6);
+
+        assert_eq!(chunks[0]["content"], "ünïcöd…");
+        assert_eq!(chunks[0]["uri"], "notes/long-document-name.md");
+        assert_eq!(chunks[1]["content"], "short");
+    }
+}
@@ -0,0 +1,92 @@
+//! Test helpers - scripted mock of the Graphiti HTTP server
+//!
+//! `MockGraphiti` binds a local `TcpListener` and answers every request with the next
+//! scripted `(status, body)` pair (the last pair repeats once the script runs out).
+//! Connections are closed after each response, so every client call is one request.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+/// Scripted mock Graphiti server
+pub struct MockGraphiti {
+    pub base_url: String,
+    requests: Arc<AtomicUsize>,
+}
+
+impl MockGraphiti {
+    /// Start a mock that answers immediately
+    pub async fn start(responses: Vec<(u16, &'static str)>) -> Self {
+        Self::start_with_delay(responses, Duration::ZERO).await
+    }
+
+    /// Start a mock that waits `delay` before answering each request
+    pub async fn start_with_delay(responses: Vec<(u16, &'static str)>, delay: Duration) -> Self {
+        assert!(
+            !responses.is_empty(),
+            "MockGraphiti needs at least one response"
+        );
+
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind mock listener");
+        let base_url = format!("http://{}", listener.local_addr().expect("local addr"));
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&requests);
+
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                read_request(&mut stream).await;
+                let index = counter.fetch_add(1, Ordering::SeqCst);
+                let (status, body) = responses[index.min(responses.len() - 1)];
+
+                tokio::time::sleep(delay).await;
+                let response = format!(
+                    "HTTP/1.1 {status} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                let _ = stream.write_all(response.as_bytes()).await;
+                let _ = stream.shutdown().await;
+            }
+        });
+
+        Self { base_url, requests }
+    }
+
+    /// Number of requests received so far
+    pub fn requests(&self) -> usize {
+        self.requests.load(Ordering::SeqCst)
+    }
+}
+
+/// Read one full HTTP request (headers plus `Content-Length` body) so closing the
+/// socket afterwards doesn't reset the connection under the client
+async fn read_request(stream: &mut TcpStream) {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 1024];
+
+    loop {
+        let Ok(n) = stream.read(&mut chunk).await else {
+            return;
+        };
+        if n == 0 {
+            return;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+
+        let Some(header_end) = buf.windows(4).position(|w| w == b"\r\n\r\n") else {
+            continue;
+        };
+        let headers = String::from_utf8_lossy(&buf[..header_end]).to_ascii_lowercase();
+        let content_length = headers
+            .lines()
+            .find_map(|line| line.strip_prefix("content-length:"))
+            .and_then(|value| value.trim().parse::<usize>().ok())
+            .unwrap_or(0);
+        if buf.len() >= header_end + 4 + content_length {
+            return;
+        }
+    }
+}
@@ -54,6 +54,9 @@ pub struct AddMemoryRequest {
 pub struct GetEpisodesRequest {
     #[schemars(description = "Recent episodes to retrieve (default: 10)")]
     pub last_n: Option<usize>,
+
+    #[schemars(description = "Truncate episode content to N chars (default: server config)")]
+    pub preview_chars: Option<usize>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -69,6 +72,9 @@ pub struct SearchContextRequest {
 
     #[schemars(description = "Max nodes (default: 5, facts: 2x)")]
     pub max_results: Option<usize>,
+
+    #[schemars(description = "Truncate summaries and facts to N chars (default: server config)")]
+    pub preview_chars: Option<usize>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -86,4 +92,7 @@ pub struct GetChunksRequest {
 
     #[schemars(description = "Reranking query (cross-encoder)")]
     pub rerank_query: Option<String>,
+
+    #[schemars(description = "Truncate chunk content to N chars (default: server config)")]
+    pub preview_chars: Option<usize>,
 }